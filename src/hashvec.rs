@@ -1,22 +1,29 @@
 use alloc::rc::Rc;
 use alloc::vec::Vec;
-use core::hash::Hash;
+use core::hash::{BuildHasher, Hash};
 
 use hashbrown::HashSet;
 
 use crate::utils::SeqExt;
+use crate::DefaultHashBuilder;
 
 #[derive(Clone, Debug)]
-pub struct HashVec<T: Eq + Hash> {
-    pub hash: HashSet<Rc<T>>,
+pub struct HashVec<T: Eq + Hash, S: BuildHasher + Clone = DefaultHashBuilder> {
+    pub hash: HashSet<Rc<T>, S>,
     pub vector: Vec<Rc<T>>,
     pub length: usize,
 }
 
-impl<T: Eq + Hash> HashVec<T> {
+impl<T: Eq + Hash> HashVec<T, DefaultHashBuilder> {
     pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher + Clone + Default> HashVec<T, S> {
+    pub fn with_hasher(hasher: S) -> Self {
         Self {
-            hash: HashSet::new(),
+            hash: HashSet::with_hasher(hasher),
             vector: Vec::new(),
             length: 0,
         }
@@ -44,7 +51,7 @@ impl<T: Eq + Hash> HashVec<T> {
         }
     }
 
-    pub fn merge_with_hashvec(&mut self, other_hashvec: &HashVec<T>) {
+    pub fn merge_with_hashvec(&mut self, other_hashvec: &HashVec<T, S>) {
         for value in &other_hashvec.vector {
             if !self.hash.contains(value) {
                 let rc = Rc::clone(value);
@@ -53,11 +60,51 @@ impl<T: Eq + Hash> HashVec<T> {
         }
     }
 
-    pub fn from_others(items: Vec<&HashVec<T>>) -> HashVec<T> {
-        items.into_iter().fold(HashVec::new(), |mut out, other| {
-            out.merge_with_hashvec(other);
-            out
-        })
+    pub fn from_others(items: Vec<&HashVec<T, S>>) -> HashVec<T, S> {
+        items
+            .into_iter()
+            .fold(Self::with_hasher(S::default()), |mut out, other| {
+                out.merge_with_hashvec(other);
+                out
+            })
+    }
+}
+
+impl<T: Eq + Hash, S: BuildHasher + Clone + Default> Default for HashVec<T, S> {
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+// Only the ordered `vector` is serialized: `hash` and `length` are derived from it and are
+// rebuilt through `add_if_not_exists` on deserialize, which both restores `Rc` sharing and
+// re-enforces the uniqueness invariant against a hand-crafted payload.
+#[cfg(feature = "serde")]
+impl<T: Eq + Hash + serde::Serialize, S: BuildHasher + Clone> serde::Serialize for HashVec<T, S> {
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        use serde::ser::SerializeSeq;
+
+        let mut seq = serializer.serialize_seq(Some(self.vector.len()))?;
+        for rc in &self.vector {
+            seq.serialize_element(rc.as_ref())?;
+        }
+        seq.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, T: Eq + Hash + serde::Deserialize<'de>, S: BuildHasher + Clone + Default>
+    serde::Deserialize<'de> for HashVec<T, S>
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        let values = Vec::<T>::deserialize(deserializer)?;
+        let mut hash_vec = Self::with_hasher(S::default());
+
+        for value in values {
+            hash_vec.add_if_not_exists(value);
+        }
+
+        Ok(hash_vec)
     }
 }
 
@@ -68,4 +115,39 @@ macro_rules! hashvec {
         $(temp_vec.add(Rc::new($x));)*
         temp_vec
     }};
-}
\ No newline at end of file
+}
+
+#[cfg(all(test, feature = "serde"))]
+mod tests {
+    use super::*;
+
+    #[derive(Clone, Debug, Eq, PartialEq, Hash, serde::Serialize, serde::Deserialize)]
+    struct Item(usize);
+
+    #[test]
+    fn hash_vec_serde_round_trip_dedups() {
+        let mut hash_vec: HashVec<Item> = HashVec::new();
+        hash_vec.add_if_not_exists(Item(1));
+        hash_vec.add_if_not_exists(Item(1));
+        hash_vec.add_if_not_exists(Item(2));
+
+        let encoded = bincode::serialize(&hash_vec).unwrap();
+        let decoded: HashVec<Item> = bincode::deserialize(&encoded).unwrap();
+
+        assert_eq!(decoded.length, 2);
+        assert_eq!(decoded.vector.len(), 2);
+        assert!(decoded.hash.contains(&Item(1)));
+        assert!(decoded.hash.contains(&Item(2)));
+    }
+
+    #[test]
+    fn empty_hash_vec_serde_round_trip() {
+        let hash_vec: HashVec<Item> = HashVec::new();
+
+        let encoded = bincode::serialize(&hash_vec).unwrap();
+        let decoded: HashVec<Item> = bincode::deserialize(&encoded).unwrap();
+
+        assert_eq!(decoded.length, 0);
+        assert!(decoded.vector.is_empty());
+    }
+}
@@ -1,91 +1,210 @@
-use core::hash::Hash;
+extern crate alloc;
+
+use core::hash::{BuildHasher, Hash};
 use std::collections::HashMap;
 
-use hashbrown::HashSet;
+use hashbrown::{Equivalent, HashMap as HashbrownMap, HashSet};
+
+pub mod hashvec;
+pub mod utils;
+
+/// The hasher used by [`Table`], [`InverseTable`] and [`HashVec`] when no `S` is chosen
+/// explicitly. Mirrors hashbrown's own `DefaultHashBuilder`: `ahash` when the `ahash`
+/// feature is enabled (fast, not DoS-resistant), the standard library's `RandomState`
+/// (SipHash) otherwise.
+#[cfg(feature = "ahash")]
+pub type DefaultHashBuilder = ahash::RandomState;
+#[cfg(not(feature = "ahash"))]
+pub type DefaultHashBuilder = std::collections::hash_map::RandomState;
 
 pub trait TableKV: Eq + PartialEq + Hash {
     fn id(&self) -> usize;
 }
 
 #[derive(Clone, Debug)]
-pub struct Table<C: TableKV, R: TableKV, V: TableKV> {
+pub struct Table<C: TableKV, R: TableKV, V: TableKV, S: BuildHasher + Clone = DefaultHashBuilder> {
     // Intersection of column and row has these values
-    pub tuples: HashMap<(usize, usize), HashSet<usize>>,
+    pub tuples: HashMap<(usize, usize), HashSet<usize, S>, S>,
     // A given column has these values with all rows
-    pub cols2values: HashMap<usize, HashSet<usize>>,
+    pub cols2values: HashMap<usize, HashSet<usize, S>, S>,
     // A given row has these values with all columns
-    pub rows2values: HashMap<usize, HashSet<usize>>,
-    pub values: HashMap<usize, V>,
-    pub cols: HashMap<usize, C>,
-    pub rows: HashMap<usize, R>,
+    pub rows2values: HashMap<usize, HashSet<usize, S>, S>,
+    pub values: HashMap<usize, V, S>,
+    pub cols: HashMap<usize, C, S>,
+    pub rows: HashMap<usize, R, S>,
+    // Reverse of `cols`/`rows`, letting `get_by_column`/`get_by_row` resolve a borrowed key to
+    // its `usize` id in O(1) instead of scanning `cols`/`rows`.
+    col_index: HashbrownMap<C, usize, S>,
+    row_index: HashbrownMap<R, usize, S>,
+    // How many tuples under a given column/row currently hold a given value_key, so `remove` can
+    // decide in O(1) whether `value_key` is still referenced elsewhere under that column/row
+    // instead of rescanning every tuple in the table.
+    column_value_refcounts: HashMap<(usize, usize), usize, S>,
+    row_value_refcounts: HashMap<(usize, usize), usize, S>,
 }
 
-impl<C: TableKV, R: TableKV, V: TableKV> Table<C, R, V> {
+impl<C: TableKV, R: TableKV, V: TableKV> Table<C, R, V, DefaultHashBuilder> {
     pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: TableKV, R: TableKV, V: TableKV, S: BuildHasher + Clone + Default> Table<C, R, V, S> {
+    pub fn with_hasher(hasher: S) -> Self {
         Table {
-            tuples: HashMap::new(),
-            cols2values: HashMap::new(),
-            rows2values: HashMap::new(),
-            values: HashMap::new(),
-            cols: HashMap::new(),
-            rows: HashMap::new(),
+            tuples: HashMap::with_hasher(hasher.clone()),
+            cols2values: HashMap::with_hasher(hasher.clone()),
+            rows2values: HashMap::with_hasher(hasher.clone()),
+            values: HashMap::with_hasher(hasher.clone()),
+            cols: HashMap::with_hasher(hasher.clone()),
+            rows: HashMap::with_hasher(hasher.clone()),
+            col_index: HashbrownMap::with_hasher(hasher.clone()),
+            row_index: HashbrownMap::with_hasher(hasher.clone()),
+            column_value_refcounts: HashMap::with_hasher(hasher.clone()),
+            row_value_refcounts: HashMap::with_hasher(hasher),
         }
     }
 
-    pub fn insert(&mut self, column: C, row: R, value: V) {
+    pub fn insert(&mut self, column: C, row: R, value: V)
+    where
+        C: Clone,
+        R: Clone,
+    {
         let column_key = column.id();
         let row_key = row.id();
 
         self.insert_value(column_key, row_key, value);
-        self.cols.insert(column_key, column);
-        self.rows.insert(row_key, row);
+        self.reindex_column(column_key, column);
+        self.reindex_row(row_key, row);
     }
 
-    pub fn insert_column_value(&mut self, column: C, row_key: usize, value: V) {
+    pub fn insert_column_value(&mut self, column: C, row_key: usize, value: V)
+    where
+        C: Clone,
+    {
         // It is assumed here that row has already been inserted previously
         let column_key = column.id();
 
         self.insert_value(column_key, row_key, value);
-        self.cols.insert(column_key, column);
+        self.reindex_column(column_key, column);
     }
 
-    pub fn insert_row_value(&mut self, column_key: usize, row: R, value: V) {
+    pub fn insert_row_value(&mut self, column_key: usize, row: R, value: V)
+    where
+        R: Clone,
+    {
         // It is assumed here that column has already been inserted previously
         let row_key = row.id();
 
         self.insert_value(column_key, row_key, value);
-        self.rows.insert(row_key, row);
+        self.reindex_row(row_key, row);
+    }
+
+    // `column_key` may already be taken by a `column` that is `Eq`-different from the one being
+    // inserted (same id, different value) - `cols` just overwrites it, but `col_index` is keyed
+    // off the value itself, so the superseded value's entry must be dropped or it would outlive
+    // its id and resolve to the wrong column forever after.
+    fn reindex_column(&mut self, column_key: usize, column: C)
+    where
+        C: Clone,
+    {
+        if let Some(old_column) = self.cols.insert(column_key, column.clone()) {
+            if old_column != column {
+                self.col_index.remove(&old_column);
+            }
+        }
+        self.col_index.insert(column, column_key);
+    }
+
+    // See `reindex_column`.
+    fn reindex_row(&mut self, row_key: usize, row: R)
+    where
+        R: Clone,
+    {
+        if let Some(old_row) = self.rows.insert(row_key, row.clone()) {
+            if old_row != row {
+                self.row_index.remove(&old_row);
+            }
+        }
+        self.row_index.insert(row, row_key);
     }
 
     pub fn insert_value(&mut self, column_key: usize, row_key: usize, value: V) {
         let column_row_key = (column_key, row_key);
         let value_key = value.id();
+        let hasher = self.tuples.hasher().clone();
+
+        let newly_in_tuple = self
+            .tuples
+            .entry(column_row_key)
+            .or_insert_with(|| HashSet::with_hasher(hasher.clone()))
+            .insert(value_key);
 
-        self.tuples.entry(column_row_key).or_insert_with(HashSet::new).insert(value_key);
-        self.cols2values.entry(column_key).or_insert_with(HashSet::new).insert(value_key);
-        self.rows2values.entry(row_key).or_insert_with(HashSet::new).insert(value_key);
+        // Only a tuple that didn't already hold `value_key` grows the refcount - a duplicate
+        // `insert` of the same `(column_key, row_key, value_key)` must not inflate it, or a
+        // single `remove` would then believe `value_key` is still referenced elsewhere.
+        if newly_in_tuple {
+            increment_refcount(&mut self.column_value_refcounts, (column_key, value_key));
+            increment_refcount(&mut self.row_value_refcounts, (row_key, value_key));
+        }
+
+        self.cols2values
+            .entry(column_key)
+            .or_insert_with(|| HashSet::with_hasher(hasher.clone()))
+            .insert(value_key);
+        self.rows2values
+            .entry(row_key)
+            .or_insert_with(|| HashSet::with_hasher(hasher))
+            .insert(value_key);
 
         self.values.insert(value_key, value);
     }
 
-    pub fn remove(&mut self, column_key: usize, row_key: usize, value_key: usize) {
+    pub fn remove(&mut self, column_key: usize, row_key: usize, value_key: usize) -> Option<V> {
+        let tuple_had_value = self
+            .tuples
+            .get(&(column_key, row_key))
+            .is_some_and(|value_keys| value_keys.contains(&value_key));
+
         remove_from_set_and_map(&mut self.tuples, &(column_key, row_key), &value_key);
-        remove_from_set_and_map(&mut self.cols2values, &column_key, &value_key);
-        remove_from_set_and_map(&mut self.rows2values, &row_key, &value_key);
 
-        self.values.remove(&value_key);
+        // `cols2values`/`rows2values` are unions across every row/column, so `value_key` can
+        // only be dropped from them once no *other* tuple under this column/row still holds it -
+        // `column_value_refcounts`/`row_value_refcounts` track that incrementally so this is O(1)
+        // instead of rescanning every tuple in the table.
+        if tuple_had_value {
+            let value_elsewhere_in_column =
+                decrement_refcount(&mut self.column_value_refcounts, (column_key, value_key));
+            if !value_elsewhere_in_column {
+                remove_from_set_and_map(&mut self.cols2values, &column_key, &value_key);
+            }
+
+            let value_elsewhere_in_row =
+                decrement_refcount(&mut self.row_value_refcounts, (row_key, value_key));
+            if !value_elsewhere_in_row {
+                remove_from_set_and_map(&mut self.rows2values, &row_key, &value_key);
+            }
+        }
+
+        let removed_value = self.values.remove(&value_key);
 
         if !self.cols2values.contains_key(&column_key) {
-            self.cols.remove(&column_key);
+            if let Some(column) = self.cols.remove(&column_key) {
+                self.col_index.remove(&column);
+            }
         }
 
         if !self.rows2values.contains_key(&row_key) {
-            self.rows.remove(&row_key);
+            if let Some(row) = self.rows.remove(&row_key) {
+                self.row_index.remove(&row);
+            }
         }
+
+        removed_value
     }
 
     pub fn remove_by_row(&mut self, row_key_to_remove: usize) {
-        let mut items_to_remove = HashSet::<(usize, usize)>::new();
+        let mut items_to_remove = HashSet::with_hasher(self.tuples.hasher().clone());
 
         for (tuple, vals) in &self.tuples {
             if tuple.1 == row_key_to_remove {
@@ -102,7 +221,7 @@ impl<C: TableKV, R: TableKV, V: TableKV> Table<C, R, V> {
     }
 
     pub fn remove_by_column(&mut self, column_key_to_remove: usize) {
-        let mut items_to_remove = HashSet::<(usize, usize)>::new();
+        let mut items_to_remove = HashSet::with_hasher(self.tuples.hasher().clone());
 
         for (tuple, vals) in &self.tuples {
             if tuple.0 == column_key_to_remove {
@@ -118,33 +237,332 @@ impl<C: TableKV, R: TableKV, V: TableKV> Table<C, R, V> {
         }
     }
 
+    // The gather phase below has no cross-entry dependency and parallelizes cleanly; the
+    // mutation phase that follows still runs sequentially through `remove` so `tuples`,
+    // `cols2values` and `rows2values` stay coherent.
+    #[cfg(feature = "rayon")]
+    pub fn remove_by_row_par(&mut self, row_key_to_remove: usize)
+    where
+        S: Sync,
+    {
+        use rayon::prelude::*;
+
+        let items_to_remove: HashSet<(usize, usize), S> = self
+            .tuples
+            .par_iter()
+            .filter(|(tuple, _)| tuple.1 == row_key_to_remove)
+            .flat_map_iter(|(tuple, vals)| vals.iter().map(move |value_key| (tuple.0, *value_key)))
+            .collect();
+
+        for (column_key, value_key) in items_to_remove {
+            self.remove(column_key, row_key_to_remove, value_key);
+        }
+    }
+
+    #[cfg(feature = "rayon")]
+    pub fn remove_by_column_par(&mut self, column_key_to_remove: usize)
+    where
+        S: Sync,
+    {
+        use rayon::prelude::*;
+
+        let items_to_remove: HashSet<(usize, usize), S> = self
+            .tuples
+            .par_iter()
+            .filter(|(tuple, _)| tuple.0 == column_key_to_remove)
+            .flat_map_iter(|(tuple, vals)| vals.iter().map(move |value_key| (tuple.1, *value_key)))
+            .collect();
+
+        for (row_key, value_key) in items_to_remove {
+            self.remove(column_key_to_remove, row_key, value_key);
+        }
+    }
+
+    // The matching pass is read-only so it can walk `tuples` freely before any mutation; shared by
+    // `extract_if` and `retain` so that `retain` (which doesn't hand anything back to the caller)
+    // doesn't pay for cloning values it's only going to throw away.
+    fn matching_keys<F: FnMut(usize, usize, &V) -> bool>(
+        &self,
+        mut pred: F,
+    ) -> Vec<(usize, usize, usize)> {
+        let mut keys = Vec::new();
+
+        for (&(column_key, row_key), value_keys) in &self.tuples {
+            for &value_key in value_keys {
+                let matches = self
+                    .values
+                    .get(&value_key)
+                    .is_some_and(|value| pred(column_key, row_key, value));
+
+                if matches {
+                    keys.push((column_key, row_key, value_key));
+                }
+            }
+        }
+
+        keys
+    }
+
+    // Every removal is funneled through `remove`, so `tuples`, `cols2values`, `rows2values`,
+    // `values`, `cols` and `rows` all stay coherent - including dropping now-empty `cols`/`rows`
+    // entries.
+    //
+    // `value_key` is shared whenever the same value id lives at more than one tuple, and `remove`
+    // unconditionally evicts the id from the global `values` registry on its *first* call for that
+    // id - so every matched tuple's value is cloned out before any removal happens, guaranteeing
+    // one `(column_key, row_key, V)` per removed tuple regardless of how many tuples share an id.
+    pub fn extract_if<F: FnMut(usize, usize, &V) -> bool>(
+        &mut self,
+        pred: F,
+    ) -> Vec<(usize, usize, V)>
+    where
+        V: Clone,
+    {
+        let matched: Vec<_> = self
+            .matching_keys(pred)
+            .into_iter()
+            .filter_map(|(column_key, row_key, value_key)| {
+                let value = self.values.get(&value_key)?.clone();
+                Some((column_key, row_key, value_key, value))
+            })
+            .collect();
+
+        for &(column_key, row_key, value_key, _) in &matched {
+            self.remove(column_key, row_key, value_key);
+        }
+
+        matched
+            .into_iter()
+            .map(|(column_key, row_key, _, value)| (column_key, row_key, value))
+            .collect()
+    }
+
+    pub fn retain<F: FnMut(usize, usize, &V) -> bool>(&mut self, mut pred: F) {
+        let keys_to_remove =
+            self.matching_keys(|column_key, row_key, value| !pred(column_key, row_key, value));
+
+        for (column_key, row_key, value_key) in keys_to_remove {
+            self.remove(column_key, row_key, value_key);
+        }
+    }
+
     pub fn is_empty(&self) -> bool {
-        let all_empty = self.tuples.is_empty() && self.cols2values.is_empty() && self.rows2values.is_empty();
-        let all_non_empty = !self.tuples.is_empty() && !self.cols2values.is_empty() && !self.rows2values.is_empty();
+        let all_empty =
+            self.tuples.is_empty() && self.cols2values.is_empty() && self.rows2values.is_empty();
+        let all_non_empty =
+            !self.tuples.is_empty() && !self.cols2values.is_empty() && !self.rows2values.is_empty();
         assert!(all_empty || all_non_empty);
         all_empty
     }
 }
 
+impl<C: TableKV, R: TableKV, V: TableKV, S: BuildHasher + Clone + Default> Default
+    for Table<C, R, V, S>
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
+}
+
+impl<C: TableKV, R: TableKV, V: TableKV, S: BuildHasher + Clone> Table<C, R, V, S> {
+    /// Values stored at the intersection of `column_key` and `row_key`.
+    pub fn values_at(&self, column_key: usize, row_key: usize) -> impl Iterator<Item = &V> {
+        self.tuples
+            .get(&(column_key, row_key))
+            .into_iter()
+            .flatten()
+            .filter_map(move |value_key| self.values.get(value_key))
+    }
+
+    /// All values stored anywhere under `column_key`, across every row.
+    pub fn column_values(&self, column_key: usize) -> impl Iterator<Item = &V> {
+        self.cols2values
+            .get(&column_key)
+            .into_iter()
+            .flatten()
+            .filter_map(move |value_key| self.values.get(value_key))
+    }
+
+    /// All values stored anywhere under `row_key`, across every column.
+    pub fn row_values(&self, row_key: usize) -> impl Iterator<Item = &V> {
+        self.rows2values
+            .get(&row_key)
+            .into_iter()
+            .flatten()
+            .filter_map(move |value_key| self.values.get(value_key))
+    }
+
+    /// Values that `column_key` and `row_key` have in common, i.e. `column_values(column_key)`
+    /// values that are *not* in the column's "except" set for this tuple. `inverse` must have
+    /// been built from (or incrementally maintained against) `self`.
+    pub fn values_in_both<'a>(
+        &'a self,
+        inverse: &'a InverseTable<S>,
+        column_key: usize,
+        row_key: usize,
+    ) -> impl Iterator<Item = &'a V> + 'a {
+        let except = inverse.column_value_keys_except.get(&(column_key, row_key));
+        // A pair that is not a tuple has no except set; fall back to the row's own values.
+        let row_value_keys = self.rows2values.get(&row_key);
+
+        self.cols2values
+            .get(&column_key)
+            .into_iter()
+            .flatten()
+            .filter(move |value_key| match except {
+                Some(e) => !e.contains(*value_key),
+                None => row_value_keys.is_some_and(|r| r.contains(*value_key)),
+            })
+            .filter_map(move |value_key| self.values.get(value_key))
+    }
+
+    /// Looks up a column by a borrowed key type rather than its raw `usize` id, following
+    /// hashbrown's `Equivalent`/`Borrow` lookup convention, keeping the `usize` keying an
+    /// internal implementation detail. Resolves through `col_index` in O(1), not a scan over
+    /// `cols`.
+    pub fn get_by_column<Q: Hash + Equivalent<C> + ?Sized>(
+        &self,
+        query: &Q,
+    ) -> Option<impl Iterator<Item = &V>> {
+        let column_key = *self.col_index.get(query)?;
+        Some(self.column_values(column_key))
+    }
+
+    /// Looks up a row by a borrowed key type rather than its raw `usize` id. See [`Self::get_by_column`].
+    pub fn get_by_row<Q: Hash + Equivalent<R> + ?Sized>(
+        &self,
+        query: &Q,
+    ) -> Option<impl Iterator<Item = &V>> {
+        let row_key = *self.row_index.get(query)?;
+        Some(self.row_values(row_key))
+    }
+}
+
+// Only `tuples`, `values`, `cols` and `rows` are serialized: `cols2values`, `rows2values`, the
+// `col_index`/`row_index` reverse lookups and the `column_value_refcounts`/`row_value_refcounts`
+// bookkeeping are all derived from `tuples`/`cols`/`rows` and are rebuilt on deserialize so they
+// can never disagree with a tampered-with or hand-crafted payload.
+#[cfg(feature = "serde")]
+impl<C, R, V, S> serde::Serialize for Table<C, R, V, S>
+where
+    C: TableKV + serde::Serialize,
+    R: TableKV + serde::Serialize,
+    V: TableKV + serde::Serialize,
+    S: BuildHasher + Clone,
+{
+    fn serialize<Ser: serde::Serializer>(&self, serializer: Ser) -> Result<Ser::Ok, Ser::Error> {
+        use serde::ser::SerializeStruct;
+
+        let mut state = serializer.serialize_struct("Table", 4)?;
+        state.serialize_field("values", &self.values)?;
+        state.serialize_field("cols", &self.cols)?;
+        state.serialize_field("rows", &self.rows)?;
+        state.serialize_field("tuples", &self.tuples)?;
+        state.end()
+    }
+}
+
+#[cfg(feature = "serde")]
+impl<'de, C, R, V, S> serde::Deserialize<'de> for Table<C, R, V, S>
+where
+    C: TableKV + serde::Deserialize<'de> + Clone,
+    R: TableKV + serde::Deserialize<'de> + Clone,
+    V: TableKV + serde::Deserialize<'de>,
+    S: BuildHasher + Clone + Default,
+{
+    fn deserialize<D: serde::Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(serde::Deserialize)]
+        #[serde(bound(
+            deserialize = "C: serde::Deserialize<'de>, R: serde::Deserialize<'de>, V: serde::Deserialize<'de>, S: BuildHasher + Default"
+        ))]
+        struct TableData<C, R, V, S: BuildHasher> {
+            values: HashMap<usize, V, S>,
+            cols: HashMap<usize, C, S>,
+            rows: HashMap<usize, R, S>,
+            tuples: HashMap<(usize, usize), HashSet<usize, S>, S>,
+        }
+
+        let data = TableData::<C, R, V, S>::deserialize(deserializer)?;
+        let hasher = data.values.hasher().clone();
+        let mut cols2values = HashMap::with_hasher(hasher.clone());
+        let mut rows2values = HashMap::with_hasher(hasher.clone());
+        let mut col_index = HashbrownMap::with_hasher(hasher.clone());
+        let mut row_index = HashbrownMap::with_hasher(hasher.clone());
+        let mut column_value_refcounts = HashMap::with_hasher(hasher.clone());
+        let mut row_value_refcounts = HashMap::with_hasher(hasher.clone());
+
+        for (&column_key, column) in &data.cols {
+            col_index.insert(column.clone(), column_key);
+        }
+        for (&row_key, row) in &data.rows {
+            row_index.insert(row.clone(), row_key);
+        }
+
+        for (&(column_key, row_key), value_keys) in &data.tuples {
+            cols2values
+                .entry(column_key)
+                .or_insert_with(|| HashSet::with_hasher(hasher.clone()))
+                .extend(value_keys.iter().copied());
+            rows2values
+                .entry(row_key)
+                .or_insert_with(|| HashSet::with_hasher(hasher.clone()))
+                .extend(value_keys.iter().copied());
+
+            for &value_key in value_keys {
+                increment_refcount(&mut column_value_refcounts, (column_key, value_key));
+                increment_refcount(&mut row_value_refcounts, (row_key, value_key));
+            }
+        }
+
+        Ok(Table {
+            tuples: data.tuples,
+            cols2values,
+            rows2values,
+            values: data.values,
+            cols: data.cols,
+            rows: data.rows,
+            col_index,
+            row_index,
+            column_value_refcounts,
+            row_value_refcounts,
+        })
+    }
+}
+
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[cfg_attr(
+    feature = "serde",
+    serde(bound(
+        serialize = "S: BuildHasher + Clone",
+        deserialize = "S: BuildHasher + Clone + Default"
+    ))
+)]
 #[derive(Clone, Debug)]
-pub struct InverseTable {
+pub struct InverseTable<S: BuildHasher + Clone = DefaultHashBuilder> {
     // This column has these values except those at intersection with this row
-    pub column_value_keys_except: HashMap<(usize, usize), HashSet<usize>>,
+    pub column_value_keys_except: HashMap<(usize, usize), HashSet<usize, S>, S>,
     // This row has these values except those at intersection with this column
-    pub row_value_keys_except: HashMap<(usize, usize), HashSet<usize>>,
+    pub row_value_keys_except: HashMap<(usize, usize), HashSet<usize, S>, S>,
 }
 
-impl InverseTable {
-    pub fn rebuild_from<C: TableKV, R: TableKV, V: TableKV>(table: &Table<C, R, V>) -> Self {
-        let mut column_value_keys_except = HashMap::<(usize, usize), HashSet<usize>>::new();
-        let mut row_value_keys_except = HashMap::<(usize, usize), HashSet<usize>>::new();
+impl<S: BuildHasher + Clone + Default> InverseTable<S> {
+    pub fn rebuild_from<C: TableKV, R: TableKV, V: TableKV>(table: &Table<C, R, V, S>) -> Self {
+        let hasher = table.tuples.hasher().clone();
+        let mut column_value_keys_except = HashMap::with_hasher(hasher.clone());
+        let mut row_value_keys_except = HashMap::with_hasher(hasher);
 
         for key @ (column_key, row_key) in table.tuples.keys() {
             let column_value_keys = table.cols2values.get(column_key).unwrap();
             let row_value_keys = table.rows2values.get(row_key).unwrap();
 
-            let column_values_diff = column_value_keys.difference(row_value_keys).cloned().collect();
-            let row_values_diff = row_value_keys.difference(column_value_keys).cloned().collect();
+            let column_values_diff = column_value_keys
+                .difference(row_value_keys)
+                .cloned()
+                .collect();
+            let row_values_diff = row_value_keys
+                .difference(column_value_keys)
+                .cloned()
+                .collect();
 
             column_value_keys_except.insert(*key, column_values_diff);
             row_value_keys_except.insert(*key, row_values_diff);
@@ -155,10 +573,392 @@ impl InverseTable {
             row_value_keys_except,
         }
     }
+
+    // Each `(column_key, row_key)` entry computes its pair of set differences independently of
+    // every other entry, so the gather phase parallelizes cleanly over `rayon`; only the
+    // sequential insert into the two result maps needs `&mut`. This operates purely on the
+    // `usize`-keyed `cols2values`/`rows2values` maps, so it carries no constraint on `C`, `R`
+    // or `V` beyond `Sync` - it never touches `Rc`-shared value storage such as `HashVec`.
+    #[cfg(feature = "rayon")]
+    pub fn rebuild_from_par<C, R, V>(table: &Table<C, R, V, S>) -> Self
+    where
+        C: TableKV + Sync,
+        R: TableKV + Sync,
+        V: TableKV + Sync,
+        S: Sync + Send,
+    {
+        use rayon::prelude::*;
+
+        // One entry per tuple: the tuple's key, its column-except-row value diff, and its
+        // row-except-column value diff, gathered in parallel before the sequential insert below.
+        type TupleDiff<S> = ((usize, usize), HashSet<usize, S>, HashSet<usize, S>);
+
+        let diffs: Vec<TupleDiff<S>> = table
+            .tuples
+            .par_iter()
+            .map(|(&(column_key, row_key), _)| {
+                let column_value_keys = table.cols2values.get(&column_key).unwrap();
+                let row_value_keys = table.rows2values.get(&row_key).unwrap();
+
+                let column_values_diff = column_value_keys
+                    .difference(row_value_keys)
+                    .cloned()
+                    .collect();
+                let row_values_diff = row_value_keys
+                    .difference(column_value_keys)
+                    .cloned()
+                    .collect();
+
+                ((column_key, row_key), column_values_diff, row_values_diff)
+            })
+            .collect();
+
+        let hasher = table.tuples.hasher().clone();
+        let mut column_value_keys_except = HashMap::with_hasher(hasher.clone());
+        let mut row_value_keys_except = HashMap::with_hasher(hasher);
+
+        for (key, column_values_diff, row_values_diff) in diffs {
+            column_value_keys_except.insert(key, column_values_diff);
+            row_value_keys_except.insert(key, row_values_diff);
+        }
+
+        InverseTable {
+            column_value_keys_except,
+            row_value_keys_except,
+        }
+    }
+}
+
+/// A [`Table`] ridden by its [`InverseTable`], updated in place on every `insert`/`remove`
+/// instead of recomputed from scratch via [`InverseTable::rebuild_from`]. `column_rows` and
+/// `row_cols` are a private adjacency index (column key -> row keys sharing it, and the
+/// mirror) so each call only touches the handful of tuples that share the affected column or
+/// row, rather than every tuple in the table.
+#[derive(Clone, Debug)]
+pub struct TableWithInverse<
+    C: TableKV,
+    R: TableKV,
+    V: TableKV,
+    S: BuildHasher + Clone = DefaultHashBuilder,
+> {
+    pub table: Table<C, R, V, S>,
+    pub inverse: InverseTable<S>,
+    column_rows: HashMap<usize, HashSet<usize, S>, S>,
+    row_cols: HashMap<usize, HashSet<usize, S>, S>,
+}
+
+impl<C: TableKV, R: TableKV, V: TableKV> TableWithInverse<C, R, V, DefaultHashBuilder> {
+    pub fn new() -> Self {
+        Self::default()
+    }
+}
+
+impl<C: TableKV, R: TableKV, V: TableKV, S: BuildHasher + Clone + Default>
+    TableWithInverse<C, R, V, S>
+{
+    pub fn with_hasher(hasher: S) -> Self {
+        TableWithInverse {
+            table: Table::with_hasher(hasher.clone()),
+            inverse: InverseTable {
+                column_value_keys_except: HashMap::with_hasher(hasher.clone()),
+                row_value_keys_except: HashMap::with_hasher(hasher.clone()),
+            },
+            column_rows: HashMap::with_hasher(hasher.clone()),
+            row_cols: HashMap::with_hasher(hasher),
+        }
+    }
+
+    pub fn insert(&mut self, column: C, row: R, value: V)
+    where
+        C: Clone,
+        R: Clone,
+    {
+        let column_key = column.id();
+        let row_key = row.id();
+        let value_key = value.id();
+        let hasher = self.table.tuples.hasher().clone();
+
+        self.table.insert(column, row, value);
+
+        let rows_sharing_column = self
+            .column_rows
+            .entry(column_key)
+            .or_insert_with(|| HashSet::with_hasher(hasher.clone()));
+        rows_sharing_column.insert(row_key);
+        let touched_rows: Vec<usize> = rows_sharing_column.iter().copied().collect();
+
+        let cols_sharing_row = self
+            .row_cols
+            .entry(row_key)
+            .or_insert_with(|| HashSet::with_hasher(hasher.clone()));
+        cols_sharing_row.insert(column_key);
+        let touched_cols: Vec<usize> = cols_sharing_row.iter().copied().collect();
+
+        // `value_key` now belongs to both `cols2values[column_key]` and `rows2values[row_key]`,
+        // so every other tuple sharing the column or the row must re-evaluate whether
+        // `value_key` still belongs in its "except" set - both the set keyed off the axis that
+        // just gained `value_key` (the minuend) and the set keyed off the other axis, for which
+        // `value_key` is a new member of the subtrahend.
+        for r in touched_rows {
+            if r == row_key {
+                continue;
+            }
+
+            let contained_in_row = self
+                .table
+                .rows2values
+                .get(&r)
+                .is_some_and(|keys| keys.contains(&value_key));
+
+            let column_except = self
+                .inverse
+                .column_value_keys_except
+                .entry((column_key, r))
+                .or_insert_with(|| HashSet::with_hasher(hasher.clone()));
+
+            if contained_in_row {
+                column_except.remove(&value_key);
+            } else {
+                column_except.insert(value_key);
+            }
+
+            // `value_key` joined `cols2values[column_key]`, the subtrahend of this set; a
+            // growing subtrahend can only shrink the difference, never grow it.
+            if contained_in_row {
+                if let Some(row_except) =
+                    self.inverse.row_value_keys_except.get_mut(&(column_key, r))
+                {
+                    row_except.remove(&value_key);
+                }
+            }
+        }
+
+        for c in touched_cols {
+            if c == column_key {
+                continue;
+            }
+
+            let contained_in_col = self
+                .table
+                .cols2values
+                .get(&c)
+                .is_some_and(|keys| keys.contains(&value_key));
+
+            let row_except = self
+                .inverse
+                .row_value_keys_except
+                .entry((c, row_key))
+                .or_insert_with(|| HashSet::with_hasher(hasher.clone()));
+
+            if contained_in_col {
+                row_except.remove(&value_key);
+            } else {
+                row_except.insert(value_key);
+            }
+
+            // `value_key` joined `rows2values[row_key]`, the subtrahend of this set; a growing
+            // subtrahend can only shrink the difference, never grow it.
+            if contained_in_col {
+                if let Some(column_except) =
+                    self.inverse.column_value_keys_except.get_mut(&(c, row_key))
+                {
+                    column_except.remove(&value_key);
+                }
+            }
+        }
+
+        self.recompute_tuple(column_key, row_key);
+    }
+
+    pub fn remove(&mut self, column_key: usize, row_key: usize, value_key: usize) -> Option<V> {
+        // `Table::remove`'s `Option<V>` return reflects whether the *shared, id-keyed* `V`
+        // instance was evicted from the registry, which happens on the first removal of
+        // `value_key` from any tuple - it is `None` on a later removal of the same id from
+        // another tuple, even though that `(column_key, row_key)` association is only now
+        // gone. Decide whether to run the bookkeeping below from the tuple's own membership
+        // instead.
+        let tuple_had_value = self
+            .table
+            .tuples
+            .get(&(column_key, row_key))
+            .is_some_and(|value_keys| value_keys.contains(&value_key));
+
+        let removed_value = self.table.remove(column_key, row_key, value_key);
+
+        if !tuple_had_value {
+            return removed_value;
+        }
+
+        let touched_rows: Vec<usize> = self
+            .column_rows
+            .get(&column_key)
+            .map(|rows| rows.iter().copied().collect())
+            .unwrap_or_default();
+
+        for r in touched_rows {
+            if r == row_key {
+                continue;
+            }
+
+            let still_in_col = self
+                .table
+                .cols2values
+                .get(&column_key)
+                .is_some_and(|keys| keys.contains(&value_key));
+            let still_in_row = self
+                .table
+                .rows2values
+                .get(&r)
+                .is_some_and(|keys| keys.contains(&value_key));
+
+            if let Some(except) = self
+                .inverse
+                .column_value_keys_except
+                .get_mut(&(column_key, r))
+            {
+                if still_in_col && !still_in_row {
+                    except.insert(value_key);
+                } else {
+                    except.remove(&value_key);
+                }
+            }
+
+            if let Some(except) = self
+                .inverse
+                .row_value_keys_except
+                .get_mut(&(column_key, r))
+            {
+                if still_in_row && !still_in_col {
+                    except.insert(value_key);
+                } else {
+                    except.remove(&value_key);
+                }
+            }
+        }
+
+        let touched_cols: Vec<usize> = self
+            .row_cols
+            .get(&row_key)
+            .map(|cols| cols.iter().copied().collect())
+            .unwrap_or_default();
+
+        for c in touched_cols {
+            if c == column_key {
+                continue;
+            }
+
+            let still_in_row = self
+                .table
+                .rows2values
+                .get(&row_key)
+                .is_some_and(|keys| keys.contains(&value_key));
+            let still_in_col = self
+                .table
+                .cols2values
+                .get(&c)
+                .is_some_and(|keys| keys.contains(&value_key));
+
+            if let Some(except) = self.inverse.row_value_keys_except.get_mut(&(c, row_key)) {
+                if still_in_row && !still_in_col {
+                    except.insert(value_key);
+                } else {
+                    except.remove(&value_key);
+                }
+            }
+
+            if let Some(except) = self.inverse.column_value_keys_except.get_mut(&(c, row_key)) {
+                if still_in_col && !still_in_row {
+                    except.insert(value_key);
+                } else {
+                    except.remove(&value_key);
+                }
+            }
+        }
+
+        self.recompute_tuple(column_key, row_key);
+
+        if !self.table.tuples.contains_key(&(column_key, row_key)) {
+            if let Some(rows) = self.column_rows.get_mut(&column_key) {
+                rows.remove(&row_key);
+                if rows.is_empty() {
+                    self.column_rows.remove(&column_key);
+                }
+            }
+
+            if let Some(cols) = self.row_cols.get_mut(&row_key) {
+                cols.remove(&column_key);
+                if cols.is_empty() {
+                    self.row_cols.remove(&row_key);
+                }
+            }
+        }
+
+        removed_value
+    }
+
+    // Recomputes the touched tuple's own except-sets from scratch, identically to
+    // `InverseTable::rebuild_from` for that single `(column_key, row_key)` entry. Gated on the
+    // tuple itself still existing in `self.table.tuples` rather than on `cols2values`/
+    // `rows2values` alone, since those can stay populated on the column/row's account of some
+    // other tuple even after `(column_key, row_key)` itself is gone.
+    fn recompute_tuple(&mut self, column_key: usize, row_key: usize) {
+        if !self.table.tuples.contains_key(&(column_key, row_key)) {
+            self.inverse
+                .column_value_keys_except
+                .remove(&(column_key, row_key));
+            self.inverse
+                .row_value_keys_except
+                .remove(&(column_key, row_key));
+            return;
+        }
+
+        match (
+            self.table.cols2values.get(&column_key),
+            self.table.rows2values.get(&row_key),
+        ) {
+            (Some(column_value_keys), Some(row_value_keys)) => {
+                let column_values_diff = column_value_keys
+                    .difference(row_value_keys)
+                    .cloned()
+                    .collect();
+                let row_values_diff = row_value_keys
+                    .difference(column_value_keys)
+                    .cloned()
+                    .collect();
+
+                self.inverse
+                    .column_value_keys_except
+                    .insert((column_key, row_key), column_values_diff);
+                self.inverse
+                    .row_value_keys_except
+                    .insert((column_key, row_key), row_values_diff);
+            }
+            _ => {
+                self.inverse
+                    .column_value_keys_except
+                    .remove(&(column_key, row_key));
+                self.inverse
+                    .row_value_keys_except
+                    .remove(&(column_key, row_key));
+            }
+        }
+    }
+}
+
+impl<C: TableKV, R: TableKV, V: TableKV, S: BuildHasher + Clone + Default> Default
+    for TableWithInverse<C, R, V, S>
+{
+    fn default() -> Self {
+        Self::with_hasher(S::default())
+    }
 }
 
 // A utility to remove a value from HashSet which is a value of HashMap, and then remove a HashMap key if set becomes empty
-pub fn remove_from_set_and_map<K: Eq + Hash, V: Eq + Hash>(map: &mut HashMap<K, HashSet<V>>, key: &K, value: &V) {
+pub fn remove_from_set_and_map<K: Eq + Hash, V: Eq + Hash, S: BuildHasher>(
+    map: &mut HashMap<K, HashSet<V, S>, S>,
+    key: &K,
+    value: &V,
+) {
     if let Some(inner_set) = map.get_mut(key) {
         inner_set.remove(value);
 
@@ -168,11 +968,35 @@ pub fn remove_from_set_and_map<K: Eq + Hash, V: Eq + Hash>(map: &mut HashMap<K,
     }
 }
 
+fn increment_refcount<K: Eq + Hash, S: BuildHasher>(map: &mut HashMap<K, usize, S>, key: K) {
+    *map.entry(key).or_insert(0) += 1;
+}
+
+// Decrements the refcount for `key`, removing the entry once it reaches zero. Returns whether
+// `key` is still referenced elsewhere (i.e. the refcount is still above zero).
+fn decrement_refcount<K: Eq + Hash, S: BuildHasher>(
+    map: &mut HashMap<K, usize, S>,
+    key: K,
+) -> bool {
+    match map.get_mut(&key) {
+        Some(count) if *count > 1 => {
+            *count -= 1;
+            true
+        }
+        Some(_) => {
+            map.remove(&key);
+            false
+        }
+        None => false,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
 
-    #[derive(Debug, Eq, PartialEq, Hash)]
+    #[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+    #[derive(Clone, Debug, Eq, PartialEq, Hash)]
     struct Container(usize);
 
     impl TableKV for Container {
@@ -191,7 +1015,7 @@ mod tests {
 
         assert_eq!(table.tuples.get(&(1, 2)).unwrap().len(), 2);
         assert_eq!(table.tuples.get(&(1, 3)).unwrap().len(), 1);
-        assert!(table.tuples.get(&(4, 2)).is_none());
+        assert!(!table.tuples.contains_key(&(4, 2)));
     }
 
     #[test]
@@ -203,6 +1027,24 @@ mod tests {
         assert!(table.is_empty());
     }
 
+    #[test]
+    fn remove_keeps_value_elsewhere_in_column_or_row() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(3), Container(11));
+
+        table.remove(1, 2, 11);
+
+        assert!(!table.tuples.contains_key(&(1, 2)));
+        assert!(table.tuples.contains_key(&(1, 3)));
+        assert!(table.cols2values.get(&1).unwrap().contains(&11));
+        assert!(!table.rows2values.contains_key(&2));
+
+        table.remove(1, 3, 11);
+
+        assert!(table.is_empty());
+    }
+
     #[test]
     fn remove_by_column() {
         let mut table: Table<Container, Container, Container> = Table::new();
@@ -227,12 +1069,12 @@ mod tests {
 
     #[test]
     fn inverse_table() {
-        let hs0 = HashSet::<usize>::new();
-        let mut hs1 = HashSet::<usize>::new();
+        let hs0 = HashSet::<usize, DefaultHashBuilder>::default();
+        let mut hs1 = HashSet::<usize, DefaultHashBuilder>::default();
         hs1.insert(12);
         hs1.insert(15);
 
-        let mut hs2 = HashSet::<usize>::new();
+        let mut hs2 = HashSet::<usize, DefaultHashBuilder>::default();
         hs2.insert(17);
 
         let mut table: Table<Container, Container, Container> = Table::new();
@@ -247,6 +1089,233 @@ mod tests {
         assert_eq!(inverse.row_value_keys_except.get(&(2, 6)).unwrap(), &hs0);
         assert_eq!(inverse.column_value_keys_except.get(&(1, 3)).unwrap(), &hs1);
         assert_eq!(inverse.row_value_keys_except.get(&(4, 5)).unwrap(), &hs2);
-        assert!(inverse.row_value_keys_except.get(&(4, 6)).is_none());
+        assert!(!inverse.row_value_keys_except.contains_key(&(4, 6)));
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn table_serde_round_trip() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(3), Container(12));
+
+        let encoded = bincode::serialize(&table).unwrap();
+        let decoded: Table<Container, Container, Container> =
+            bincode::deserialize(&encoded).unwrap();
+
+        assert_eq!(decoded.tuples, table.tuples);
+        assert_eq!(decoded.cols2values, table.cols2values);
+        assert_eq!(decoded.rows2values, table.rows2values);
+        assert_eq!(
+            decoded.values.keys().collect::<HashSet<_>>(),
+            table.values.keys().collect()
+        );
+    }
+
+    #[cfg(feature = "serde")]
+    #[test]
+    fn empty_table_serde_round_trip() {
+        let table: Table<Container, Container, Container> = Table::new();
+
+        let encoded = bincode::serialize(&table).unwrap();
+        let decoded: Table<Container, Container, Container> =
+            bincode::deserialize(&encoded).unwrap();
+
+        assert!(decoded.is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn rebuild_from_par_matches_rebuild_from() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(12));
+        table.insert(Container(1), Container(3), Container(14));
+        table.insert(Container(1), Container(2), Container(15));
+        table.insert(Container(4), Container(5), Container(16));
+        table.insert(Container(2), Container(5), Container(17));
+        table.insert(Container(2), Container(6), Container(17));
+
+        let sequential = InverseTable::rebuild_from(&table);
+        let parallel = InverseTable::rebuild_from_par(&table);
+
+        assert_eq!(
+            parallel.column_value_keys_except,
+            sequential.column_value_keys_except
+        );
+        assert_eq!(
+            parallel.row_value_keys_except,
+            sequential.row_value_keys_except
+        );
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn remove_by_row_par_matches_remove_by_row() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(2), Container(1), Container(11));
+        table.insert(Container(3), Container(1), Container(12));
+        table.insert(Container(4), Container(1), Container(13));
+        table.insert(Container(5), Container(1), Container(14));
+        table.remove_by_row_par(1);
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn extract_if_removes_everything() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(3), Container(12));
+
+        let extracted = table.extract_if(|_, _, _| true);
+
+        assert_eq!(extracted.len(), 2);
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn extract_if_reports_every_tuple_sharing_a_value_id() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(3), Container(11));
+
+        let mut extracted = table.extract_if(|_, _, _| true);
+        extracted.sort_by_key(|&(_, row_key, _)| row_key);
+
+        assert_eq!(
+            extracted,
+            vec![(1, 2, Container(11)), (1, 3, Container(11))]
+        );
+        assert!(table.is_empty());
+    }
+
+    #[test]
+    fn extract_if_removes_half() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(2), Container(12));
+        table.insert(Container(1), Container(3), Container(13));
+
+        let extracted = table.extract_if(|_, _, value| value.0 >= 13);
+
+        assert_eq!(extracted, vec![(1, 3, Container(13))]);
+        assert_eq!(table.tuples.get(&(1, 2)).unwrap().len(), 2);
+        assert!(!table.tuples.contains_key(&(1, 3)));
+        assert_eq!(table.cols2values.get(&1).unwrap().len(), 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn retain_keeps_matching_entries() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(3), Container(12));
+
+        table.retain(|_, _, value| value.0 == 11);
+
+        assert_eq!(table.values.len(), 1);
+        assert!(table.values.contains_key(&11));
+    }
+
+    #[test]
+    fn incremental_inverse_matches_rebuild_from() {
+        // A small deterministic LCG stands in for a property-test RNG so this stays dependency-free.
+        let mut seed: u64 = 0x2545_f491_4f6c_dd1d;
+        let mut next = move || {
+            seed = seed
+                .wrapping_mul(6364136223846793005)
+                .wrapping_add(1442695040888963407);
+            (seed >> 33) as usize
+        };
+
+        let mut with_inverse: TableWithInverse<Container, Container, Container> =
+            TableWithInverse::new();
+        let mut inserted = Vec::<(usize, usize, usize)>::new();
+
+        for _ in 0..200 {
+            let column_key = next() % 6;
+            let row_key = next() % 6;
+            let value_key = next() % 20;
+
+            if inserted.is_empty() || next() % 3 != 0 {
+                with_inverse.insert(
+                    Container(column_key),
+                    Container(row_key),
+                    Container(value_key),
+                );
+                inserted.push((column_key, row_key, value_key));
+            } else {
+                let index = next() % inserted.len();
+                let (column_key, row_key, value_key) = inserted.remove(index);
+                with_inverse.remove(column_key, row_key, value_key);
+            }
+
+            let rebuilt = InverseTable::rebuild_from(&with_inverse.table);
+            assert_eq!(
+                with_inverse.inverse.column_value_keys_except,
+                rebuilt.column_value_keys_except
+            );
+            assert_eq!(
+                with_inverse.inverse.row_value_keys_except,
+                rebuilt.row_value_keys_except
+            );
+        }
+    }
+
+    #[test]
+    fn values_at_and_column_row_values() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+        table.insert(Container(1), Container(3), Container(12));
+
+        let at = table.values_at(1, 2).map(|v| v.0).collect::<Vec<_>>();
+        assert_eq!(at, vec![11]);
+
+        let mut column = table.column_values(1).map(|v| v.0).collect::<Vec<_>>();
+        column.sort();
+        assert_eq!(column, vec![11, 12]);
+
+        let row = table.row_values(2).map(|v| v.0).collect::<Vec<_>>();
+        assert_eq!(row, vec![11]);
+    }
+
+    #[test]
+    fn values_in_both_uses_inverse_table() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(12));
+        table.insert(Container(1), Container(3), Container(14));
+        table.insert(Container(1), Container(2), Container(15));
+        table.insert(Container(4), Container(5), Container(16));
+        table.insert(Container(2), Container(5), Container(17));
+        table.insert(Container(2), Container(6), Container(17));
+
+        let inverse = InverseTable::rebuild_from(&table);
+        let mut shared = table
+            .values_in_both(&inverse, 1, 2)
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        shared.sort();
+        assert_eq!(shared, vec![12, 15]);
+    }
+
+    #[test]
+    fn get_by_column_and_row_resolve_through_cols_and_rows() {
+        let mut table: Table<Container, Container, Container> = Table::new();
+        table.insert(Container(1), Container(2), Container(11));
+
+        let by_column = table
+            .get_by_column(&Container(1))
+            .unwrap()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        assert_eq!(by_column, vec![11]);
+
+        let by_row = table
+            .get_by_row(&Container(2))
+            .unwrap()
+            .map(|v| v.0)
+            .collect::<Vec<_>>();
+        assert_eq!(by_row, vec![11]);
+
+        assert!(table.get_by_column(&Container(99)).is_none());
+    }
+}